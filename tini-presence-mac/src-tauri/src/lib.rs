@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
 use tauri::{
     menu::{Menu, MenuItem},
     tray::{TrayIconBuilder, TrayIconEvent},
@@ -6,6 +8,176 @@ use tauri::{
 };
 use tauri_plugin_shell::{process::CommandChild, ShellExt};
 
+mod secret {
+    //! At-rest encryption for the copyparty API key.
+    //!
+    //! The key is never persisted in the clear: we derive a symmetric key with
+    //! Argon2id from a machine-bound secret and seal the credential with
+    //! ChaCha20-Poly1305, storing only `{salt, nonce, ciphertext}`. The sealed
+    //! blob is the app's own at-rest store (written under the app config dir by
+    //! [`persist_secret`] and reloaded on startup); the plaintext is
+    //! reconstructed lazily, in memory, only when it has to be forwarded to the
+    //! sidecar.
+    //!
+    //! Forwarding to the sidecar is a transient, in-memory hand-off: the
+    //! sidecar owns its own config persistence and is responsible for not
+    //! writing the key to disk in the clear. Sealing it here protects the
+    //! app-owned copy at rest; it does not and cannot retroactively secure a
+    //! plaintext copy the sidecar chooses to persist.
+
+    use argon2::Argon2;
+    use chacha20poly1305::aead::{Aead, KeyInit};
+    use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+    use rand::RngCore;
+
+    /// A sealed credential as stored in `AppConfig`.
+    #[derive(Clone, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct EncryptedSecret {
+        pub salt: Vec<u8>,
+        pub nonce: Vec<u8>,
+        pub ciphertext: Vec<u8>,
+    }
+
+    /// A machine-bound secret used as the Argon2id password. We prefer the
+    /// macOS hardware UUID (stable per machine, not world-readable over the
+    /// network) and fall back to the hostname so derivation never hard-fails.
+    fn machine_secret() -> Vec<u8> {
+        if let Ok(output) = std::process::Command::new("ioreg")
+            .args(["-rd1", "-c", "IOPlatformExpertDevice"])
+            .output()
+        {
+            if let Ok(text) = String::from_utf8(output.stdout) {
+                if let Some(line) = text.lines().find(|l| l.contains("IOPlatformUUID")) {
+                    if let Some(uuid) = line.split('"').nth(3) {
+                        return uuid.as_bytes().to_vec();
+                    }
+                }
+            }
+        }
+        std::env::var("HOSTNAME")
+            .or_else(|_| std::env::var("HOST"))
+            .unwrap_or_else(|_| "tini-presence".to_string())
+            .into_bytes()
+    }
+
+    /// Derive a 32-byte key from the machine secret and the per-secret salt.
+    fn derive_key(salt: &[u8]) -> Result<[u8; 32], String> {
+        let mut key = [0u8; 32];
+        Argon2::default()
+            .hash_password_into(&machine_secret(), salt, &mut key)
+            .map_err(|err| err.to_string())?;
+        Ok(key)
+    }
+
+    /// Seal `plaintext` into an `EncryptedSecret`.
+    pub fn encrypt(plaintext: &str) -> Result<EncryptedSecret, String> {
+        let mut salt = [0u8; 16];
+        let mut nonce_bytes = [0u8; 12];
+        rand::rngs::OsRng.fill_bytes(&mut salt);
+        rand::rngs::OsRng.fill_bytes(&mut nonce_bytes);
+
+        let key = derive_key(&salt)?;
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce_bytes), plaintext.as_bytes())
+            .map_err(|err| err.to_string())?;
+
+        Ok(EncryptedSecret {
+            salt: salt.to_vec(),
+            nonce: nonce_bytes.to_vec(),
+            ciphertext,
+        })
+    }
+
+    /// Recover the plaintext from a sealed credential.
+    pub fn decrypt(secret: &EncryptedSecret) -> Result<String, String> {
+        let key = derive_key(&secret.salt)?;
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&secret.nonce), secret.ciphertext.as_ref())
+            .map_err(|err| err.to_string())?;
+        String::from_utf8(plaintext).map_err(|err| err.to_string())
+    }
+}
+
+use secret::EncryptedSecret;
+
+/// Placeholder surfaced to the webview in place of a stored credential.
+const REDACTED_KEY: &str = "••••";
+
+/// File name (without extension) of the rotating log under the app log dir.
+const LOG_FILE_NAME: &str = "tini-presence";
+/// Default rotation threshold, used until the config supplies `log_max_size`.
+const LOG_DEFAULT_MAX_SIZE: u128 = 5 * 1024 * 1024;
+/// File name of the app-owned store holding the rotation settings, read at
+/// startup (before the log plugin is built) and rewritten when the config
+/// changes.
+const LOG_SETTINGS_FILE: &str = "log-settings.json";
+
+/// Rotation settings the log plugin is configured with at startup. They live in
+/// their own at-rest store because the plugin is built once, before an
+/// `AppHandle` exists, so there is no other way to carry a user preference
+/// across launches.
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LogSettings {
+    max_size: Option<u64>,
+    max_files: Option<u32>,
+}
+
+/// Compute the rotation-settings path from the bundle identifier alone (no
+/// `AppHandle` required), mirroring the macOS `app_config_dir` layout so it
+/// matches what [`persist_log_settings`] writes at runtime.
+fn log_settings_path(identifier: &str) -> Option<std::path::PathBuf> {
+    let home = std::env::var_os("HOME")?;
+    Some(
+        std::path::PathBuf::from(home)
+            .join("Library/Application Support")
+            .join(identifier)
+            .join(LOG_SETTINGS_FILE),
+    )
+}
+
+/// Load the persisted rotation settings, falling back to defaults.
+fn load_log_settings(identifier: &str) -> LogSettings {
+    log_settings_path(identifier)
+        .and_then(|path| std::fs::read(path).ok())
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+/// Persist the rotation settings so the next launch configures the log plugin
+/// with the user's retention size/count.
+fn persist_log_settings(app: &tauri::AppHandle, config: &AppConfig) -> Result<(), String> {
+    let dir = app.path().app_config_dir().map_err(|err| err.to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+    let settings = LogSettings {
+        max_size: config.log_max_size,
+        max_files: config.log_max_files,
+    };
+    let json = serde_json::to_vec(&settings).map_err(|err| err.to_string())?;
+    std::fs::write(dir.join(LOG_SETTINGS_FILE), json).map_err(|err| err.to_string())
+}
+
+/// Tee a lifecycle message into the persistent log sink (tagged with the given
+/// level and source via the `log` facade) while still emitting the live
+/// `sidecar-log` event for the webview.
+fn log_event(app: &tauri::AppHandle, level: log::Level, source: &str, message: &str) {
+    match level {
+        log::Level::Error => log::error!(target: source, "{message}"),
+        log::Level::Warn => log::warn!(target: source, "{message}"),
+        _ => log::info!(target: source, "{message}"),
+    }
+    let _ = app.emit("sidecar-log", message.to_string());
+}
+
+/// Resolve the path of the active rotating log file.
+fn log_file_path(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app.path().app_log_dir().map_err(|err| err.to_string())?;
+    Ok(dir.join(format!("{LOG_FILE_NAME}.log")))
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct TrackStatus {
@@ -25,25 +197,148 @@ struct TrackStatus {
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct AppConfig {
+    music_folders: Vec<String>,
+    discord_client_id: Option<String>,
+    /// The copyparty API key sealed at rest; see the [`secret`] subsystem. The
+    /// plaintext is only reconstructed when forwarding to the sidecar and is
+    /// never serialized back to the webview.
+    copyparty_api_key: Option<EncryptedSecret>,
+    copyparty_url: Option<String>,
+    copyparty_path: Option<String>,
+    theme: Option<String>,
+    auto_launch: Option<bool>,
+    /// Rotation threshold in bytes for the persistent log file. Applied to the
+    /// log plugin on the next launch (the plugin is configured once at startup).
+    log_max_size: Option<u64>,
+    /// Number of rotated log files to retain. `1` keeps a single file; any
+    /// larger value keeps all. Applied on the next launch.
+    log_max_files: Option<u32>,
+    /// How often the watchdog pings the sidecar, in milliseconds.
+    heartbeat_interval_ms: Option<u64>,
+    /// How long the sidecar may stay silent before being treated as hung.
+    heartbeat_timeout_ms: Option<u64>,
+}
+
+/// The config as exposed to the webview: identical to [`AppConfig`] except the
+/// sealed credential is replaced by a redacted placeholder plus a `has_key`
+/// flag, so the decrypted key never reaches the frontend.
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RedactedConfig {
     music_folders: Vec<String>,
     discord_client_id: Option<String>,
     copyparty_api_key: Option<String>,
     copyparty_url: Option<String>,
     copyparty_path: Option<String>,
     theme: Option<String>,
+    auto_launch: Option<bool>,
+    log_max_size: Option<u64>,
+    log_max_files: Option<u32>,
+    heartbeat_interval_ms: Option<u64>,
+    heartbeat_timeout_ms: Option<u64>,
+    has_key: bool,
+}
+
+impl From<&AppConfig> for RedactedConfig {
+    fn from(config: &AppConfig) -> Self {
+        let has_key = config.copyparty_api_key.is_some();
+        Self {
+            music_folders: config.music_folders.clone(),
+            discord_client_id: config.discord_client_id.clone(),
+            copyparty_api_key: has_key.then(|| REDACTED_KEY.to_string()),
+            copyparty_url: config.copyparty_url.clone(),
+            copyparty_path: config.copyparty_path.clone(),
+            theme: config.theme.clone(),
+            auto_launch: config.auto_launch,
+            log_max_size: config.log_max_size,
+            log_max_files: config.log_max_files,
+            heartbeat_interval_ms: config.heartbeat_interval_ms,
+            heartbeat_timeout_ms: config.heartbeat_timeout_ms,
+            has_key,
+        }
+    }
+}
+
+/// The config as submitted by the webview: the full set of user-editable
+/// fields minus the secret, which is managed exclusively through
+/// [`set_copyparty_key`]/[`clear_copyparty_key`]. Any `copypartyApiKey` sent by
+/// the webview is ignored (the field is absent here and serde drops unknowns).
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigUpdate {
+    music_folders: Vec<String>,
+    discord_client_id: Option<String>,
+    copyparty_url: Option<String>,
+    copyparty_path: Option<String>,
+    theme: Option<String>,
+    auto_launch: Option<bool>,
+    log_max_size: Option<u64>,
+    log_max_files: Option<u32>,
+    heartbeat_interval_ms: Option<u64>,
+    heartbeat_timeout_ms: Option<u64>,
+}
+
+impl ConfigUpdate {
+    /// Combine the non-secret fields with the sealed key the app already holds
+    /// to produce the authoritative [`AppConfig`].
+    fn into_config(self, copyparty_api_key: Option<EncryptedSecret>) -> AppConfig {
+        AppConfig {
+            music_folders: self.music_folders,
+            discord_client_id: self.discord_client_id,
+            copyparty_api_key,
+            copyparty_url: self.copyparty_url,
+            copyparty_path: self.copyparty_path,
+            theme: self.theme,
+            auto_launch: self.auto_launch,
+            log_max_size: self.log_max_size,
+            log_max_files: self.log_max_files,
+            heartbeat_interval_ms: self.heartbeat_interval_ms,
+            heartbeat_timeout_ms: self.heartbeat_timeout_ms,
+        }
+    }
 }
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 struct ProtocolMessage {
     r#type: String,
+    /// Correlation id echoed back by the sidecar for replies to a specific
+    /// command. Unsolicited pushes (e.g. `status`) carry no id.
+    #[serde(default)]
+    id: Option<String>,
     payload: serde_json::Value,
 }
 
+/// How long an awaited request will wait for the sidecar's reply before
+/// returning an error rather than hanging the caller.
+const REQUEST_TIMEOUT_MS: u64 = 10_000;
+
 struct AppState {
     sidecar: Option<CommandChild>,
     is_running: bool,
     last_status: Option<TrackStatus>,
     last_config: Option<AppConfig>,
+    /// Authoritative sealed copyparty key, owned by the app independently of
+    /// whether the sidecar's `config` reply has arrived yet. `last_config`
+    /// mirrors this for redaction purposes.
+    copyparty_key: Option<EncryptedSecret>,
+    /// In-flight correlated requests awaiting a reply, keyed by correlation id.
+    pending: HashMap<String, oneshot::Sender<serde_json::Value>>,
+    /// Monotonic source of correlation ids for outbound requests.
+    next_request_id: u64,
+    /// Set by `stop_sidecar` so the termination handler can distinguish a
+    /// user-initiated shutdown from an unexpected crash.
+    user_requested_stop: bool,
+    /// Number of consecutive automatic restart attempts, used to compute the
+    /// exponential backoff delay. Reset once the sidecar runs continuously.
+    restart_attempts: u32,
+    /// Bumped on every lifecycle transition (spawn, stop, or restart) so each
+    /// sidecar incarnation has a distinct identity: a pending scheduled restart
+    /// can tell whether it has been superseded, and per-incarnation watchdog and
+    /// backoff-reset tasks exit once their generation is no longer current.
+    generation: u64,
+    /// When the sidecar last emitted any message, used by the heartbeat
+    /// watchdog to detect a hung-but-alive process.
+    last_message_at: Option<std::time::Instant>,
 }
 
 impl Default for AppState {
@@ -53,10 +348,96 @@ impl Default for AppState {
             is_running: false,
             last_status: None,
             last_config: None,
+            copyparty_key: None,
+            pending: HashMap::new(),
+            next_request_id: 0,
+            user_requested_stop: false,
+            restart_attempts: 0,
+            generation: 0,
+            last_message_at: None,
         }
     }
 }
 
+/// Base delay for the first automatic restart; doubles on each attempt.
+const RESTART_BASE_DELAY_MS: u64 = 500;
+/// Upper bound for the exponential backoff delay.
+const RESTART_MAX_DELAY_MS: u64 = 30_000;
+/// How long the sidecar must run continuously before the backoff counter is
+/// considered healthy again and reset to zero.
+const RESTART_RESET_AFTER_MS: u64 = 60_000;
+
+/// How often the watchdog pings the sidecar; overridable via
+/// `AppConfig::heartbeat_interval_ms`.
+const HEARTBEAT_INTERVAL_MS: u64 = 5_000;
+/// How long the sidecar may go without emitting any message before it is
+/// considered hung; overridable via `AppConfig::heartbeat_timeout_ms`.
+const HEARTBEAT_TIMEOUT_MS: u64 = 15_000;
+
+/// Build an `AutoLaunch` handle for this app's login-item entry. On macOS a
+/// LaunchAgent is used so the relaunch comes up detached (and therefore honours
+/// the `Accessory` activation policy set in `setup`) rather than as a
+/// foreground dock window.
+fn build_auto_launch(app: &tauri::AppHandle) -> Result<auto_launch::AutoLaunch, String> {
+    let app_name = app.package_info().name.clone();
+    let exe = std::env::current_exe().map_err(|err| err.to_string())?;
+    let exe_path = exe.to_string_lossy().to_string();
+
+    auto_launch::AutoLaunchBuilder::new()
+        .set_app_name(&app_name)
+        .set_app_path(&exe_path)
+        .set_use_launch_agent(true)
+        .build()
+        .map_err(|err| err.to_string())
+}
+
+/// Register or deregister the login-item entry so the OS matches `enabled`,
+/// returning the resulting state read back from the store.
+fn apply_auto_launch(app: &tauri::AppHandle, enabled: bool) -> Result<bool, String> {
+    let auto = build_auto_launch(app)?;
+    if enabled {
+        auto.enable().map_err(|err| err.to_string())?;
+    } else {
+        auto.disable().map_err(|err| err.to_string())?;
+    }
+    auto.is_enabled().map_err(|err| err.to_string())
+}
+
+/// Path of the app-owned sealed-key store under the app config directory.
+fn secret_store_path(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|err| err.to_string())?;
+    Ok(dir.join("copyparty-key.json"))
+}
+
+/// Write the sealed key to (or, when `None`, remove it from) the at-rest store.
+fn persist_secret(
+    app: &tauri::AppHandle,
+    secret: Option<&EncryptedSecret>,
+) -> Result<(), String> {
+    let path = secret_store_path(app)?;
+    match secret {
+        Some(secret) => {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+            }
+            let json = serde_json::to_vec(secret).map_err(|err| err.to_string())?;
+            std::fs::write(&path, json).map_err(|err| err.to_string())
+        }
+        None => match std::fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.to_string()),
+        },
+    }
+}
+
+/// Load the sealed key from the at-rest store, if one was written previously.
+fn load_secret(app: &tauri::AppHandle) -> Option<EncryptedSecret> {
+    let path = secret_store_path(app).ok()?;
+    let bytes = std::fs::read(path).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
 /// Kill any orphaned sidecar processes from previous app instances
 fn kill_orphaned_sidecars() {
     // Use pkill to kill any existing tini-presence-core processes
@@ -69,13 +450,31 @@ fn kill_orphaned_sidecars() {
 }
 
 fn start_sidecar(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>) {
+    spawn_sidecar(app, state, true);
+}
+
+/// Spawn the sidecar and wire up its event loop. When `kill_orphans` is false
+/// (the automatic-restart path) the destructive `pkill` sweep is skipped, so it
+/// runs only on a user- or startup-initiated start and not on every retry.
+fn spawn_sidecar(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>, kill_orphans: bool) {
     let mut state_guard = state.lock().unwrap();
     if state_guard.is_running {
         return;
     }
 
+    // Clear the stop flag: any termination from here on is unexpected until
+    // `stop_sidecar` says otherwise.
+    state_guard.user_requested_stop = false;
+    // Bump the generation so this incarnation has its own identity: any
+    // watchdog/reset task left sleeping from a prior incarnation will see the
+    // mismatch and bow out instead of acting on the new process.
+    state_guard.generation = state_guard.generation.wrapping_add(1);
+    let generation = state_guard.generation;
+
     // Kill any orphaned sidecar processes before starting a new one
-    kill_orphaned_sidecars();
+    if kill_orphans {
+        kill_orphaned_sidecars();
+    }
 
     match app.shell().sidecar("tini-presence-core") {
         Ok(cmd) => match cmd.spawn() {
@@ -84,7 +483,80 @@ fn start_sidecar(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>) {
                 let state_for_events = state.clone();
                 let state_for_request = state.clone();
 
-                let _ = app.emit("sidecar-log", "Sidecar started".to_string());
+                log_event(app, log::Level::Info, "lifecycle", "Sidecar started");
+
+                // Once the sidecar has run continuously past the reset
+                // threshold, treat it as healthy and clear the backoff counter
+                // so transient crashes don't permanently inflate the delay.
+                let state_for_reset = state.clone();
+                tauri::async_runtime::spawn(async move {
+                    tokio::time::sleep(std::time::Duration::from_millis(RESTART_RESET_AFTER_MS))
+                        .await;
+                    let mut guard = state_for_reset.lock().unwrap();
+                    if guard.generation == generation && guard.is_running {
+                        guard.restart_attempts = 0;
+                    }
+                });
+
+                // Heartbeat watchdog: periodically ping the sidecar and treat a
+                // silent-but-alive process as crashed. The loop exits cleanly
+                // once the generation changes (stop/restart) or the process is
+                // no longer running.
+                let app_for_watchdog = app.clone();
+                let state_for_watchdog = state.clone();
+                tauri::async_runtime::spawn(async move {
+                    loop {
+                        let (interval_ms, timeout_ms) = {
+                            let guard = state_for_watchdog.lock().unwrap();
+                            if guard.generation != generation || !guard.is_running {
+                                return;
+                            }
+                            let cfg = guard.last_config.as_ref();
+                            (
+                                cfg.and_then(|c| c.heartbeat_interval_ms)
+                                    .unwrap_or(HEARTBEAT_INTERVAL_MS),
+                                cfg.and_then(|c| c.heartbeat_timeout_ms)
+                                    .unwrap_or(HEARTBEAT_TIMEOUT_MS),
+                            )
+                        };
+
+                        tokio::time::sleep(std::time::Duration::from_millis(interval_ms)).await;
+
+                        let silent_ms = {
+                            let guard = state_for_watchdog.lock().unwrap();
+                            if guard.generation != generation || !guard.is_running {
+                                return;
+                            }
+                            guard
+                                .last_message_at
+                                .map(|at| at.elapsed().as_millis() as u64)
+                        };
+
+                        // Nudge the sidecar; a fresh `status`/`pong` refreshes
+                        // `last_message_at` and keeps us healthy.
+                        let _ = send_command(&state_for_watchdog, "ping", None);
+
+                        if let Some(ms) = silent_ms {
+                            if ms > timeout_ms {
+                                log_event(
+                                    &app_for_watchdog,
+                                    log::Level::Error,
+                                    "watchdog",
+                                    &format!("No sidecar message in {ms}ms; treating as hung"),
+                                );
+                                let _ = app_for_watchdog.emit("sidecar-unhealthy", ms);
+                                // Kill the hung process; the `Terminated` handler
+                                // then drives the same backoff restart path used
+                                // for crashes.
+                                let mut guard = state_for_watchdog.lock().unwrap();
+                                if let Some(child) = guard.sidecar.take() {
+                                    let _ = child.kill();
+                                }
+                                return;
+                            }
+                        }
+                    }
+                });
 
                 tauri::async_runtime::spawn(async move {
                     let mut buffer = String::new();
@@ -99,6 +571,27 @@ fn start_sidecar(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>) {
                                         if let Ok(message) =
                                             serde_json::from_str::<ProtocolMessage>(line)
                                         {
+                                            // Any well-formed message counts as
+                                            // a heartbeat for the watchdog.
+                                            state_for_events.lock().unwrap().last_message_at =
+                                                Some(std::time::Instant::now());
+
+                                            // Route correlated replies back to
+                                            // the awaiting request; unsolicited
+                                            // pushes (no id) fall through to the
+                                            // event-emitting match below.
+                                            if let Some(id) = message.id.as_ref() {
+                                                let sender = state_for_events
+                                                    .lock()
+                                                    .unwrap()
+                                                    .pending
+                                                    .remove(id);
+                                                if let Some(tx) = sender {
+                                                    let _ = tx.send(message.payload.clone());
+                                                    buffer.drain(..=pos);
+                                                    continue;
+                                                }
+                                            }
                                             match message.r#type.as_str() {
                                                 "status" => {
                                                     let payload = message.payload.clone();
@@ -126,19 +619,67 @@ fn start_sidecar(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>) {
                                                     }
                                                 }
                                                 "config" => {
-                                                    if let Ok(parsed) =
-                                                        serde_json::from_value::<AppConfig>(
+                                                    // Parse the robust
+                                                    // `ConfigUpdate` (same as the
+                                                    // `request_config` reply path)
+                                                    // so a plaintext `copyparty_api_key`
+                                                    // echoed by the sidecar can't
+                                                    // break decoding; the app then
+                                                    // grafts its own sealed key.
+                                                    if let Ok(update) =
+                                                        serde_json::from_value::<ConfigUpdate>(
                                                             message.payload,
                                                         )
                                                     {
+                                                        let parsed;
                                                         {
                                                             let mut guard =
                                                                 state_for_events.lock().unwrap();
+                                                            parsed = update.into_config(
+                                                                guard.copyparty_key.clone(),
+                                                            );
                                                             guard.last_config =
                                                                 Some(parsed.clone());
                                                         }
-                                                        let _ = app_handle
-                                                            .emit("config-updated", parsed);
+                                                        // Reconcile the OS
+                                                        // login-item entry with
+                                                        // the stored preference
+                                                        // so the two never drift
+                                                        // (this fires on the
+                                                        // startup get-config too).
+                                                        if let Some(enabled) = parsed.auto_launch {
+                                                            if let Err(err) = apply_auto_launch(
+                                                                &app_handle,
+                                                                enabled,
+                                                            ) {
+                                                                log_event(
+                                                                    &app_handle,
+                                                                    log::Level::Error,
+                                                                    "auto-launch",
+                                                                    &format!(
+                                                                        "Failed to reconcile auto-launch: {err}"
+                                                                    ),
+                                                                );
+                                                            }
+                                                        }
+                                                        // Mirror the retention
+                                                        // preference so the next
+                                                        // launch's log plugin
+                                                        // picks it up.
+                                                        if let Err(err) =
+                                                            persist_log_settings(
+                                                                &app_handle,
+                                                                &parsed,
+                                                            )
+                                                        {
+                                                            log::warn!(
+                                                                "failed to persist log settings: {err}"
+                                                            );
+                                                        }
+                                                        let _ = app_handle.emit(
+                                                            "config-updated",
+                                                            RedactedConfig::from(&parsed),
+                                                        );
                                                     } else {
                                                         let _ = app_handle.emit(
                                                             "sidecar-log",
@@ -147,6 +688,10 @@ fn start_sidecar(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>) {
                                                         );
                                                     }
                                                 }
+                                                // Heartbeat reply: liveness is
+                                                // already recorded above, so
+                                                // nothing else to do.
+                                                "pong" => {}
                                                 _ => {
                                                     let _ = app_handle.emit(
                                                         "sidecar-log",
@@ -155,8 +700,12 @@ fn start_sidecar(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>) {
                                                 }
                                             }
                                         } else if !line.is_empty() {
-                                            let _ =
-                                                app_handle.emit("sidecar-log", line.to_string());
+                                            log_event(
+                                                &app_handle,
+                                                log::Level::Info,
+                                                "stdout",
+                                                line,
+                                            );
                                         }
                                         buffer.drain(..=pos);
                                     }
@@ -166,18 +715,42 @@ fn start_sidecar(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>) {
                                 if let Ok(text) = String::from_utf8(line) {
                                     let line = text.trim();
                                     if !line.is_empty() {
-                                        let _ = app_handle.emit("sidecar-log", line.to_string());
+                                        log_event(&app_handle, log::Level::Warn, "stderr", line);
                                     }
                                 }
                             }
                             tauri_plugin_shell::process::CommandEvent::Terminated(payload) => {
-                                let _ = app_handle.emit(
-                                    "sidecar-log",
-                                    format!("Sidecar terminated: code={:?}", payload.code),
+                                // A non-zero (or absent) exit code is an error;
+                                // a clean exit is informational.
+                                let level = if payload.code.unwrap_or(-1) == 0 {
+                                    log::Level::Info
+                                } else {
+                                    log::Level::Error
+                                };
+                                log_event(
+                                    &app_handle,
+                                    level,
+                                    "lifecycle",
+                                    &format!("Sidecar terminated: code={:?}", payload.code),
                                 );
+
+                                let user_requested = {
+                                    let mut guard = state_for_events.lock().unwrap();
+                                    guard.sidecar = None;
+                                    guard.is_running = false;
+                                    guard.user_requested_stop
+                                };
+
+                                if user_requested {
+                                    // A user-initiated stop already reset the
+                                    // lifecycle state; nothing to recover.
+                                    let _ = app_handle.emit("service-status", false);
+                                } else {
+                                    schedule_restart(&app_handle, &state_for_events);
+                                }
                             }
                             tauri_plugin_shell::process::CommandEvent::Error(err) => {
-                                let _ = app_handle.emit("sidecar-log", err);
+                                log_event(&app_handle, log::Level::Error, "sidecar", &err);
                             }
                             _ => {}
                         }
@@ -186,30 +759,93 @@ fn start_sidecar(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>) {
 
                 state_guard.sidecar = Some(child);
                 state_guard.is_running = true;
+                state_guard.last_message_at = Some(std::time::Instant::now());
                 let _ = app.emit("service-status", true);
                 println!("Started tini-presence sidecar");
                 drop(state_guard);
                 if send_command(&state_for_request, "get-config", None).is_err() {
-                    let _ = app.emit("sidecar-log", "Failed to request config".to_string());
+                    log_event(app, log::Level::Warn, "lifecycle", "Failed to request config");
                 }
             }
             Err(e) => {
-                let _ = app.emit("sidecar-log", format!("Failed to spawn sidecar: {}", e));
-                eprintln!("Failed to spawn sidecar: {}", e)
+                log_event(
+                    app,
+                    log::Level::Error,
+                    "lifecycle",
+                    &format!("Failed to spawn sidecar: {}", e),
+                );
+                eprintln!("Failed to spawn sidecar: {}", e);
+                // A spawn failure is just as unexpected as a crash; route it
+                // through the same capped-backoff recovery instead of giving up.
+                drop(state_guard);
+                schedule_restart(app, state);
             }
         },
         Err(e) => {
-            let _ = app.emit(
-                "sidecar-log",
-                format!("Failed to create sidecar command: {}", e),
+            log_event(
+                app,
+                log::Level::Error,
+                "lifecycle",
+                &format!("Failed to create sidecar command: {}", e),
             );
-            eprintln!("Failed to create sidecar command: {}", e)
+            eprintln!("Failed to create sidecar command: {}", e);
+            drop(state_guard);
+            schedule_restart(app, state);
         }
     }
 }
 
+/// Schedule an automatic restart after a capped exponential backoff delay.
+/// The delay is `min(base * 2^attempts, max_delay)`; a `sidecar-restart` event
+/// carrying the attempt count lets the frontend surface "reconnecting…". A
+/// later user-initiated `stop_sidecar` bumps the generation and cancels the
+/// pending restart before it fires.
+fn schedule_restart(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>) {
+    let (attempt, delay_ms, generation) = {
+        let mut guard = state.lock().unwrap();
+        let attempt = guard.restart_attempts;
+        let delay_ms = RESTART_BASE_DELAY_MS
+            .saturating_mul(1u64 << attempt.min(16))
+            .min(RESTART_MAX_DELAY_MS);
+        guard.restart_attempts = attempt.saturating_add(1);
+        (attempt + 1, delay_ms, guard.generation)
+    };
+
+    let _ = app.emit("service-status", false);
+    let _ = app.emit("sidecar-restart", attempt);
+    log_event(
+        app,
+        log::Level::Warn,
+        "lifecycle",
+        &format!("Sidecar crashed; restart attempt {attempt} in {delay_ms}ms"),
+    );
+
+    let app = app.clone();
+    let state = state.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+        // A user-initiated stop (or another restart) bumps the generation,
+        // which cancels this pending attempt.
+        {
+            let guard = state.lock().unwrap();
+            if guard.generation != generation || guard.user_requested_stop || guard.is_running {
+                return;
+            }
+        }
+        spawn_sidecar(&app, &state, false);
+    });
+}
+
 fn stop_sidecar(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>) {
     let mut state_guard = state.lock().unwrap();
+    // Flag the stop and bump the generation so the termination handler skips
+    // recovery and any pending scheduled restart is cancelled.
+    state_guard.user_requested_stop = true;
+    state_guard.generation = state_guard.generation.wrapping_add(1);
+    state_guard.restart_attempts = 0;
+    // Drop any in-flight requests so their awaiting callers get an error
+    // rather than blocking until the timeout.
+    state_guard.pending.clear();
     if let Some(child) = state_guard.sidecar.take() {
         let _ = child.kill();
         state_guard.is_running = false;
@@ -217,7 +853,7 @@ fn stop_sidecar(app: &tauri::AppHandle, state: &Arc<Mutex<AppState>>) {
         state_guard.last_config = None;
         let _ = app.emit("service-status", false);
         let _ = app.emit::<Option<TrackStatus>>("track-status", None);
-        let _ = app.emit::<Option<AppConfig>>("config-updated", None);
+        let _ = app.emit::<Option<RedactedConfig>>("config-updated", None);
         println!("Stopped tini-presence sidecar");
     }
 }
@@ -245,29 +881,141 @@ fn get_track_status(state: tauri::State<'_, Arc<Mutex<AppState>>>) -> Option<Tra
 }
 
 #[tauri::command]
-fn get_config(state: tauri::State<'_, Arc<Mutex<AppState>>>) -> Option<AppConfig> {
-    state.lock().unwrap().last_config.clone()
+fn get_config(state: tauri::State<'_, Arc<Mutex<AppState>>>) -> Option<RedactedConfig> {
+    state
+        .lock()
+        .unwrap()
+        .last_config
+        .as_ref()
+        .map(RedactedConfig::from)
 }
 
 #[tauri::command]
-fn request_config(state: tauri::State<'_, Arc<Mutex<AppState>>>) -> bool {
-    send_command(&state, "get-config", None).is_ok()
+async fn request_config(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, Arc<Mutex<AppState>>>,
+) -> Result<RedactedConfig, String> {
+    let value = send_request(&state, "get-config", None).await?;
+    let update: ConfigUpdate = serde_json::from_value(value).map_err(|err| err.to_string())?;
+    // `ConfigUpdate` drops any key the sidecar echoes back; preserve the sealed
+    // key the app already holds.
+    let existing_key = state.lock().unwrap().copyparty_key.clone();
+    let config = update.into_config(existing_key);
+    if let Some(enabled) = config.auto_launch {
+        apply_auto_launch(&app, enabled)?;
+    }
+    let redacted = RedactedConfig::from(&config);
+    state.lock().unwrap().last_config = Some(config);
+    Ok(redacted)
 }
 
 #[tauri::command]
-fn update_config(state: tauri::State<'_, Arc<Mutex<AppState>>>, config: AppConfig) -> bool {
-    let payload = serde_json::to_value(config).ok();
-    send_command(&state, "update-config", payload).is_ok()
+async fn update_config(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, Arc<Mutex<AppState>>>,
+    config: ConfigUpdate,
+) -> Result<RedactedConfig, String> {
+    let existing_key = state.lock().unwrap().copyparty_key.clone();
+
+    // Forward the full config to the sidecar, decrypting the key in memory only
+    // for the outbound write; this protects the app-owned copy at rest, not the
+    // sidecar's own config store (see the `secret` module docs).
+    let mut payload = serde_json::to_value(&config).map_err(|err| err.to_string())?;
+    if let Some(sealed) = &existing_key {
+        let plaintext = secret::decrypt(sealed)?;
+        payload["copypartyApiKey"] = serde_json::Value::String(plaintext);
+    }
+    send_request(&state, "update-config", Some(payload)).await?;
+
+    let new_config = config.into_config(existing_key);
+    // Keep the OS login-item entry in sync when the preference is toggled.
+    if let Some(enabled) = new_config.auto_launch {
+        apply_auto_launch(&app, enabled)?;
+    }
+    // The log plugin is configured once at startup, so stash the retention
+    // preference for the next launch rather than the sidecar round-trip above.
+    if let Err(err) = persist_log_settings(&app, &new_config) {
+        log::warn!("failed to persist log settings: {err}");
+    }
+    let redacted = RedactedConfig::from(&new_config);
+    state.lock().unwrap().last_config = Some(new_config);
+    Ok(redacted)
+}
+
+#[tauri::command]
+async fn set_copyparty_key(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, Arc<Mutex<AppState>>>,
+    key: String,
+) -> Result<(), String> {
+    let sealed = secret::encrypt(&key)?;
+    // This seals only the app-owned copy at rest: the sidecar still receives the
+    // plaintext and is responsible for its own config store (see the `secret`
+    // module docs). Forward once, then keep only the ciphertext on our side.
+    send_request(
+        &state,
+        "set-copyparty-key",
+        Some(serde_json::json!({ "copypartyApiKey": key })),
+    )
+    .await?;
+    // Seal to the app-owned at-rest store first so a crash can't leave RAM and
+    // disk disagreeing.
+    persist_secret(&app, Some(&sealed))?;
+    let mut guard = state.lock().unwrap();
+    // The app owns the sealed key regardless of whether the sidecar's first
+    // `config` reply has arrived, so it is never silently dropped.
+    guard.copyparty_key = Some(sealed.clone());
+    if let Some(cfg) = guard.last_config.as_mut() {
+        cfg.copyparty_api_key = Some(sealed);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+async fn clear_copyparty_key(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, Arc<Mutex<AppState>>>,
+) -> Result<(), String> {
+    send_request(&state, "clear-copyparty-key", None).await?;
+    persist_secret(&app, None)?;
+    let mut guard = state.lock().unwrap();
+    guard.copyparty_key = None;
+    if let Some(cfg) = guard.last_config.as_mut() {
+        cfg.copyparty_api_key = None;
+    }
+    Ok(())
 }
 
 #[tauri::command]
-fn add_folder(state: tauri::State<'_, Arc<Mutex<AppState>>>) -> bool {
-    send_command(&state, "add-folder", None).is_ok()
+async fn add_folder(state: tauri::State<'_, Arc<Mutex<AppState>>>) -> Result<(), String> {
+    send_request(&state, "add-folder", None).await.map(|_| ())
 }
 
 #[tauri::command]
-fn open_config(state: tauri::State<'_, Arc<Mutex<AppState>>>) -> bool {
-    send_command(&state, "open-config", None).is_ok()
+async fn open_config(state: tauri::State<'_, Arc<Mutex<AppState>>>) -> Result<(), String> {
+    send_request(&state, "open-config", None).await.map(|_| ())
+}
+
+#[tauri::command]
+fn set_auto_launch(app: tauri::AppHandle, enabled: bool) -> Result<bool, String> {
+    apply_auto_launch(&app, enabled)
+}
+
+#[tauri::command]
+fn get_log_path(app: tauri::AppHandle) -> Result<String, String> {
+    log_file_path(&app).map(|path| path.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+fn open_logs(app: tauri::AppHandle) -> Result<(), String> {
+    let path = log_file_path(&app)?;
+    // Reveal the log file in Finder (same `std::process::Command` style used
+    // elsewhere in this file).
+    std::process::Command::new("open")
+        .args(["-R", &path.to_string_lossy()])
+        .spawn()
+        .map(|_| ())
+        .map_err(|err| err.to_string())
 }
 
 #[tauri::command]
@@ -323,11 +1071,92 @@ fn send_command(
         .map_err(|err| err.to_string())
 }
 
+/// Send a correlated command to the sidecar and await its reply. A unique id
+/// is attached to the outbound message and registered in `pending`; the stdout
+/// reader completes the matching oneshot when the reply arrives. Returns `Err`
+/// (rather than hanging) if the sidecar is down, closes, or does not answer
+/// within `REQUEST_TIMEOUT_MS`.
+async fn send_request(
+    state: &Arc<Mutex<AppState>>,
+    command: &str,
+    payload: Option<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    let (tx, rx) = oneshot::channel();
+
+    let id = {
+        let mut guard = state.lock().unwrap();
+        guard.next_request_id = guard.next_request_id.wrapping_add(1);
+        let id = guard.next_request_id.to_string();
+
+        let message = serde_json::json!({
+            "type": "command",
+            "command": command,
+            "id": id,
+            "payload": payload,
+        });
+
+        {
+            let child = guard
+                .sidecar
+                .as_mut()
+                .ok_or_else(|| "Sidecar not running".to_string())?;
+            child
+                .write(format!("{}\n", message).as_bytes())
+                .map_err(|err| err.to_string())?;
+        }
+
+        guard.pending.insert(id.clone(), tx);
+        id
+    };
+
+    match tokio::time::timeout(std::time::Duration::from_millis(REQUEST_TIMEOUT_MS), rx).await {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(_)) => {
+            state.lock().unwrap().pending.remove(&id);
+            Err("Sidecar closed before replying".to_string())
+        }
+        Err(_) => {
+            state.lock().unwrap().pending.remove(&id);
+            Err(format!("Timed out waiting for reply to '{command}'"))
+        }
+    }
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let state = Arc::new(Mutex::new(AppState::default()));
 
+    // Read the persisted rotation settings before the plugin is built (it is
+    // configured once here, with no `AppHandle` yet); the identifier comes from
+    // the bundled context so the path matches what the app writes at runtime.
+    let context = tauri::generate_context!();
+    let log_settings = load_log_settings(&context.config().identifier);
+    let log_max_size = log_settings
+        .max_size
+        .map(u128::from)
+        .unwrap_or(LOG_DEFAULT_MAX_SIZE);
+    // A retention count of 1 keeps a single file; anything larger keeps all.
+    let rotation_strategy = match log_settings.max_files {
+        Some(1) => tauri_plugin_log::RotationStrategy::KeepOne,
+        _ => tauri_plugin_log::RotationStrategy::KeepAll,
+    };
+
     tauri::Builder::default()
+        .plugin(
+            // Persist every lifecycle/sidecar line to a rotating file under the
+            // app log dir, tagged with timestamp, level and source, while the
+            // live `sidecar-log` events keep flowing to the UI.
+            tauri_plugin_log::Builder::new()
+                .target(tauri_plugin_log::Target::new(
+                    tauri_plugin_log::TargetKind::LogDir {
+                        file_name: Some(LOG_FILE_NAME.to_string()),
+                    },
+                ))
+                .max_file_size(log_max_size)
+                .rotation_strategy(rotation_strategy)
+                .level(log::LevelFilter::Info)
+                .build(),
+        )
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_shell::init())
@@ -381,6 +1210,12 @@ pub fn run() {
                 });
             }
 
+            // Reload the sealed copyparty key from the at-rest store so the app
+            // holds it again before the first config round-trip.
+            if let Some(sealed) = load_secret(&app_handle) {
+                state.lock().unwrap().copyparty_key = Some(sealed);
+            }
+
             // Auto-start sidecar
             start_sidecar(&app_handle, &state);
 
@@ -395,9 +1230,14 @@ pub fn run() {
             update_config,
             add_folder,
             open_config,
+            set_auto_launch,
+            set_copyparty_key,
+            clear_copyparty_key,
+            get_log_path,
+            open_logs,
             quit_app
         ])
-        .build(tauri::generate_context!())
+        .build(context)
         .expect("error while building tauri application")
         .run(|app, event| {
             // Handle app exit events to ensure sidecar is stopped
@@ -405,6 +1245,10 @@ pub fn run() {
                 tauri::RunEvent::ExitRequested { .. } | tauri::RunEvent::Exit => {
                     if let Some(state) = app.try_state::<Arc<Mutex<AppState>>>() {
                         let mut guard = state.lock().unwrap();
+                        // Bump the generation and flag the stop so the watchdog
+                        // and any pending restart cancel cleanly on exit.
+                        guard.user_requested_stop = true;
+                        guard.generation = guard.generation.wrapping_add(1);
                         if let Some(child) = guard.sidecar.take() {
                             let _ = child.kill();
                             guard.is_running = false;